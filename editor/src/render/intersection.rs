@@ -0,0 +1,149 @@
+use crate::objects::{Ctx, ID};
+use crate::render::lane::{Marking, MIN_ZOOM_FOR_DETAILED_MARKINGS};
+use crate::render::{RenderOptions, Renderable};
+use ezgui::{Color, GfxCtx};
+use geojson::Feature;
+use geom::{Angle, Bounds, GPSBounds, Polygon, Pt2D, Ring};
+use map_model::{Intersection, IntersectionID, LaneType, Map, RoadID};
+
+// The innermost point where a lane touches this intersection, used to stitch adjacent sidewalks
+// together. `get_road_edges` builds one per outermost lane of each road meeting here, sorted by
+// angle around the intersection point.
+struct RoadEdge {
+    road: RoadID,
+    lane_type: LaneType,
+    inner_pt: Pt2D,
+    angle: Angle,
+}
+
+fn get_road_edges(i: &Intersection, map: &Map) -> Vec<RoadEdge> {
+    let mut edges = Vec::new();
+    for r in &i.roads {
+        let road = map.get_r(*r);
+        for id in road.all_lanes() {
+            let lane = map.get_l(id);
+            if lane.dst_i != i.id && lane.src_i != i.id {
+                continue;
+            }
+            if !road.is_outermost_lane(id) {
+                continue;
+            }
+            let inner_pt = if lane.dst_i == i.id {
+                lane.lane_center_pts.last_pt()
+            } else {
+                lane.lane_center_pts.first_pt()
+            };
+            edges.push(RoadEdge {
+                road: *r,
+                lane_type: lane.lane_type,
+                inner_pt,
+                angle: i.point.angle_to(inner_pt),
+            });
+        }
+    }
+    edges.sort_by(|a, b| {
+        a.angle
+            .normalized_degrees()
+            .partial_cmp(&b.angle.normalized_degrees())
+            .unwrap()
+    });
+    edges
+}
+
+fn is_walkable(lane_type: LaneType) -> bool {
+    lane_type == LaneType::Sidewalk
+}
+
+// Fills in the gap between adjacent sidewalks at an intersection, so the walking area looks
+// continuous instead of stopping dead at each corner.
+pub(crate) fn calculate_sidewalk_corners(i: &Intersection, map: &Map) -> Vec<Marking> {
+    let mut edges = get_road_edges(i, map);
+    if edges.is_empty() {
+        return Vec::new();
+    }
+    // Walk adjacent pairs cyclically by repeating the first edge at the end.
+    edges.push(RoadEdge {
+        road: edges[0].road,
+        lane_type: edges[0].lane_type,
+        inner_pt: edges[0].inner_pt,
+        angle: edges[0].angle,
+    });
+
+    let mut markings = Vec::new();
+    for pair in edges.windows(2) {
+        let (e1, e2) = (&pair[0], &pair[1]);
+        if e1.road == e2.road {
+            continue;
+        }
+        if !is_walkable(e1.lane_type) || !is_walkable(e2.lane_type) {
+            continue;
+        }
+
+        let ring = Ring::new(vec![e1.inner_pt, i.point, e2.inner_pt, e1.inner_pt]);
+        markings.push(Marking::Polygon {
+            polygon: ring.to_polygon(),
+            color_key: "sidewalk corner",
+            default_color: Color::grey(0.8),
+            feature_type: "sidewalk corner",
+            min_zoom: MIN_ZOOM_FOR_DETAILED_MARKINGS,
+        });
+    }
+    markings
+}
+
+pub struct DrawIntersection {
+    pub id: IntersectionID,
+    pub polygon: Polygon,
+    markings: Vec<Marking>,
+    zorder: isize,
+}
+
+impl DrawIntersection {
+    pub fn new(i: &Intersection, map: &Map) -> DrawIntersection {
+        DrawIntersection {
+            id: i.id,
+            polygon: i.polygon.clone(),
+            markings: calculate_sidewalk_corners(i, map),
+            zorder: 0,
+        }
+    }
+
+    // One Feature per marking, mirroring `DrawLane::to_geojson`.
+    pub fn to_geojson(&self, gps_bounds: &GPSBounds) -> Vec<Feature> {
+        self.markings
+            .iter()
+            .map(|m| m.to_geojson(gps_bounds))
+            .collect()
+    }
+}
+
+impl Renderable for DrawIntersection {
+    fn get_id(&self) -> ID {
+        ID::Intersection(self.id)
+    }
+
+    fn draw(&self, g: &mut GfxCtx, opts: RenderOptions, ctx: &Ctx) {
+        let color = opts
+            .color
+            .unwrap_or_else(|| ctx.cs.get_def("intersection", Color::grey(0.6)));
+        g.draw_polygon(color, &self.polygon);
+
+        for m in &self.markings {
+            if ctx.canvas.cam_zoom >= m.min_zoom() || opts.show_all_detail {
+                m.draw(g, ctx.cs);
+            }
+        }
+    }
+
+    fn get_bounds(&self) -> Bounds {
+        self.polygon.get_bounds()
+    }
+
+    fn contains_pt(&self, pt: Pt2D) -> bool {
+        self.polygon.contains_pt(pt)
+    }
+
+    fn get_zorder(&self) -> isize {
+        self.zorder
+    }
+}
@@ -6,13 +6,187 @@ use crate::render::{
 };
 use dimensioned::si;
 use ezgui::{Color, GfxCtx};
-use geom::{Bounds, Circle, Line, Polygon, Pt2D};
+use geojson::{Feature, Geometry, Value};
+use geom::{Angle, Bounds, Circle, GPSBounds, Line, Polygon, Pt2D};
 use map_model::{
-    IntersectionType, Lane, LaneID, LaneType, Map, Road, Turn, LANE_THICKNESS, PARKING_SPOT_LENGTH,
+    IntersectionType, Lane, LaneID, LaneType, Map, Road, LANE_THICKNESS, PARKING_SPOT_LENGTH,
 };
 
-// Just a function to draw something later.
-type Marking = Box<Fn(&mut GfxCtx, &ColorScheme)>;
+// Coarse orientation markings (the center line, stop lines) stay visible as soon as markings
+// start rendering at all. Everything finer -- parking legs, sidewalk treads, dashed lane lines,
+// turn arrows, crosswalk stripes -- is sub-pixel at city scale, so it waits for a much closer
+// zoom before it's worth the draw calls.
+pub(crate) const MIN_ZOOM_FOR_DETAILED_MARKINGS: f64 = MIN_ZOOM_FOR_MARKINGS * 4.0;
+
+// A single piece of paint on the ground. Unlike the old boxed-closure approach, these are plain
+// data, so they can be inspected, restyled, or exported instead of only drawn.
+pub(crate) enum Marking {
+    Line {
+        line: Line,
+        thickness: f64,
+        rounded: bool,
+        color_key: &'static str,
+        default_color: Color,
+        feature_type: &'static str,
+        min_zoom: f64,
+    },
+    DashedLine {
+        polygons: Vec<Polygon>,
+        color_key: &'static str,
+        default_color: Color,
+        feature_type: &'static str,
+        min_zoom: f64,
+    },
+    Polygon {
+        polygon: Polygon,
+        color_key: &'static str,
+        default_color: Color,
+        feature_type: &'static str,
+        min_zoom: f64,
+    },
+    Arrow {
+        line: Line,
+        thickness: f64,
+        color_key: &'static str,
+        default_color: Color,
+        feature_type: &'static str,
+        min_zoom: f64,
+    },
+}
+
+impl Marking {
+    pub(crate) fn draw(&self, g: &mut GfxCtx, cs: &ColorScheme) {
+        match self {
+            Marking::Line {
+                line,
+                thickness,
+                rounded,
+                color_key,
+                default_color,
+                ..
+            } => {
+                let color = cs.get_def(color_key, *default_color);
+                if *rounded {
+                    g.draw_rounded_line(color, *thickness, line);
+                } else {
+                    g.draw_line(color, *thickness, line);
+                }
+            }
+            Marking::DashedLine {
+                polygons,
+                color_key,
+                default_color,
+                ..
+            } => {
+                let color = cs.get_def(color_key, *default_color);
+                for p in polygons {
+                    g.draw_polygon(color, p);
+                }
+            }
+            Marking::Polygon {
+                polygon,
+                color_key,
+                default_color,
+                ..
+            } => {
+                g.draw_polygon(cs.get_def(color_key, *default_color), polygon);
+            }
+            Marking::Arrow {
+                line,
+                thickness,
+                color_key,
+                default_color,
+                ..
+            } => {
+                g.draw_arrow(cs.get_def(color_key, *default_color), *thickness, line);
+            }
+        }
+    }
+
+    pub(crate) fn min_zoom(&self) -> f64 {
+        match self {
+            Marking::Line { min_zoom, .. }
+            | Marking::DashedLine { min_zoom, .. }
+            | Marking::Polygon { min_zoom, .. }
+            | Marking::Arrow { min_zoom, .. } => *min_zoom,
+        }
+    }
+
+    // One GeoJSON Feature per marking, tagged with the kind of marking it is.
+    pub(crate) fn to_geojson(&self, gps_bounds: &GPSBounds) -> Feature {
+        let (geometry, feature_type) = match self {
+            Marking::Line {
+                line, feature_type, ..
+            }
+            | Marking::Arrow {
+                line, feature_type, ..
+            } => (
+                Geometry::new(Value::LineString(vec![
+                    pt_to_gps(line.pt1(), gps_bounds),
+                    pt_to_gps(line.pt2(), gps_bounds),
+                ])),
+                *feature_type,
+            ),
+            Marking::DashedLine {
+                polygons,
+                feature_type,
+                ..
+            } => (
+                Geometry::new(Value::MultiPolygon(
+                    polygons
+                        .iter()
+                        .map(|p| vec![ring_to_gps(p, gps_bounds)])
+                        .collect(),
+                )),
+                *feature_type,
+            ),
+            Marking::Polygon {
+                polygon,
+                feature_type,
+                ..
+            } => (
+                Geometry::new(Value::Polygon(vec![ring_to_gps(polygon, gps_bounds)])),
+                *feature_type,
+            ),
+        };
+
+        let mut properties = serde_json::Map::new();
+        properties.insert(
+            "type".to_string(),
+            serde_json::Value::String(feature_type.to_string()),
+        );
+        Feature {
+            bbox: None,
+            geometry: Some(geometry),
+            id: None,
+            properties: Some(properties),
+            foreign_members: None,
+        }
+    }
+}
+
+fn pt_to_gps(pt: Pt2D, gps_bounds: &GPSBounds) -> Vec<f64> {
+    let gps = pt.to_gps(gps_bounds);
+    vec![gps.longitude, gps.latitude]
+}
+
+fn ring_to_gps(polygon: &Polygon, gps_bounds: &GPSBounds) -> Vec<Vec<f64>> {
+    close_ring(
+        polygon
+            .points()
+            .iter()
+            .map(|pt| pt_to_gps(*pt, gps_bounds))
+            .collect(),
+    )
+}
+
+// Closes the ring if the caller didn't already repeat the first point.
+fn close_ring(mut pts: Vec<Vec<f64>>) -> Vec<Vec<f64>> {
+    if pts.first() != pts.last() {
+        pts.push(pts[0].clone());
+    }
+    pts
+}
 
 pub struct DrawLane {
     pub id: LaneID,
@@ -28,40 +202,36 @@ impl DrawLane {
 
         let mut markings: Vec<Marking> = Vec::new();
         if road.is_canonical_lane(lane.id) {
-            let lines = road.center_pts.lines();
-            markings.push(Box::new(move |g, cs| {
-                for line in &lines {
-                    g.draw_rounded_line(
-                        cs.get_def("road center line", Color::YELLOW),
-                        BIG_ARROW_THICKNESS,
-                        line,
-                    );
-                }
-            }));
+            for line in road.center_pts.lines() {
+                markings.push(Marking::Line {
+                    line,
+                    thickness: BIG_ARROW_THICKNESS,
+                    rounded: true,
+                    color_key: "road center line",
+                    default_color: Color::YELLOW,
+                    feature_type: "road center line",
+                    min_zoom: MIN_ZOOM_FOR_MARKINGS,
+                });
+            }
         }
         match lane.lane_type {
             LaneType::Sidewalk => {
-                markings.push(calculate_sidewalk_lines(lane));
+                markings.extend(calculate_sidewalk_lines(lane));
+                markings.extend(calculate_crosswalk_lines(lane, map));
             }
             LaneType::Parking => {
-                markings.push(calculate_parking_lines(lane));
+                markings.extend(calculate_parking_lines(lane));
             }
             LaneType::Driving | LaneType::Bus => {
-                if let Some(m) = calculate_driving_lines(lane, road) {
-                    markings.push(m);
-                }
-                for m in calculate_turn_markings(map, lane) {
-                    markings.push(m);
-                }
+                markings.extend(calculate_driving_lines(lane, road));
+                markings.extend(calculate_turn_markings(map, lane));
             }
             LaneType::Biking => {}
         };
         if lane.is_driving()
             && map.get_i(lane.dst_i).intersection_type == IntersectionType::StopSign
         {
-            if let Some(m) = calculate_stop_sign_line(road, lane, map) {
-                markings.push(m);
-            }
+            markings.extend(calculate_stop_sign_line(road, lane, map));
         }
 
         DrawLane {
@@ -72,6 +242,14 @@ impl DrawLane {
         }
     }
 
+    // One Feature per marking, so external tools can consume the exact geometry we paint.
+    pub fn to_geojson(&self, gps_bounds: &GPSBounds) -> Vec<Feature> {
+        self.markings
+            .iter()
+            .map(|m| m.to_geojson(gps_bounds))
+            .collect()
+    }
+
     fn draw_debug(&self, g: &mut GfxCtx, ctx: &Ctx) {
         let circle_color = ctx
             .cs
@@ -107,9 +285,9 @@ impl Renderable for DrawLane {
         });
         g.draw_polygon(color, &self.polygon);
 
-        if ctx.canvas.cam_zoom >= MIN_ZOOM_FOR_MARKINGS || opts.show_all_detail {
-            for m in &self.markings {
-                m(g, ctx.cs);
+        for m in &self.markings {
+            if ctx.canvas.cam_zoom >= m.min_zoom() || opts.show_all_detail {
+                m.draw(g, ctx.cs);
             }
         }
 
@@ -138,35 +316,76 @@ fn perp_line(l: Line, length: f64) -> Line {
     Line::new(pt1, pt2)
 }
 
-fn calculate_sidewalk_lines(lane: &Lane) -> Marking {
+fn calculate_sidewalk_lines(lane: &Lane) -> Vec<Marking> {
     let tile_every = LANE_THICKNESS * si::M;
 
     let length = lane.length();
 
-    let mut lines = Vec::new();
+    let mut markings = Vec::new();
     // Start away from the intersections
     let mut dist_along = tile_every;
     while dist_along < length - tile_every {
         let (pt, angle) = lane.dist_along(dist_along);
         // Reuse perp_line. Project away an arbitrary amount
         let pt2 = pt.project_away(1.0, angle);
-        lines.push(perp_line(Line::new(pt, pt2), LANE_THICKNESS));
+        markings.push(Marking::Line {
+            line: perp_line(Line::new(pt, pt2), LANE_THICKNESS),
+            thickness: 0.25,
+            rounded: false,
+            color_key: "sidewalk lines",
+            default_color: Color::grey(0.7),
+            feature_type: "sidewalk line",
+            min_zoom: MIN_ZOOM_FOR_DETAILED_MARKINGS,
+        });
         dist_along += tile_every;
     }
 
-    Box::new(move |g, cs| {
-        for line in &lines {
-            g.draw_line(cs.get_def("sidewalk lines", Color::grey(0.7)), 0.25, line);
+    markings
+}
+
+// A zebra-striped crosswalk band on the sidewalk approach to a signalized intersection.
+fn calculate_crosswalk_lines(lane: &Lane, map: &Map) -> Vec<Marking> {
+    if map.get_i(lane.dst_i).intersection_type != IntersectionType::TrafficSignal {
+        return Vec::new();
+    }
+
+    let band_depth = 1.8 * si::M;
+    let stripe_every = 0.6 * si::M;
+    let stripe_thickness = 0.3;
+
+    let len = lane.length();
+    let band_start = len - band_depth;
+    if lane.safe_dist_along(band_start).is_none() {
+        return Vec::new();
+    }
+
+    let mut markings = Vec::new();
+    let mut dist_along = band_start;
+    while dist_along < len {
+        if let Some((pt, angle)) = lane.safe_dist_along(dist_along) {
+            // Reuse perp_line. Project away an arbitrary amount
+            let pt2 = pt.project_away(1.0, angle);
+            let stripe = perp_line(Line::new(pt, pt2), LANE_THICKNESS);
+            markings.push(Marking::Polygon {
+                polygon: stripe.make_polygons(stripe_thickness),
+                color_key: "crosswalk",
+                default_color: Color::WHITE,
+                feature_type: "crosswalk",
+                min_zoom: MIN_ZOOM_FOR_DETAILED_MARKINGS,
+            });
         }
-    })
+        dist_along += stripe_every;
+    }
+
+    markings
 }
 
-fn calculate_parking_lines(lane: &Lane) -> Marking {
+fn calculate_parking_lines(lane: &Lane) -> Vec<Marking> {
     // meters, but the dims get annoying below to remove
     // TODO make Pt2D natively understand meters, projecting away by an angle
     let leg_length = 1.0;
 
-    let mut lines = Vec::new();
+    let mut markings = Vec::new();
     let num_spots = lane.number_parking_spots();
     if num_spots > 0 {
         for idx in 0..=num_spots {
@@ -177,21 +396,25 @@ fn calculate_parking_lines(lane: &Lane) -> Marking {
             let t_pt = pt.project_away(LANE_THICKNESS * 0.4, perp_angle);
             // The perp leg
             let p1 = t_pt.project_away(leg_length, perp_angle.opposite());
-            lines.push(Line::new(t_pt, p1));
             // Upper leg
             let p2 = t_pt.project_away(leg_length, lane_angle);
-            lines.push(Line::new(t_pt, p2));
             // Lower leg
             let p3 = t_pt.project_away(leg_length, lane_angle.opposite());
-            lines.push(Line::new(t_pt, p3));
+            for p in &[p1, p2, p3] {
+                markings.push(Marking::Line {
+                    line: Line::new(t_pt, *p),
+                    thickness: 0.25,
+                    rounded: false,
+                    color_key: "parking line",
+                    default_color: Color::WHITE,
+                    feature_type: "parking line",
+                    min_zoom: MIN_ZOOM_FOR_DETAILED_MARKINGS,
+                });
+            }
         }
     }
 
-    Box::new(move |g, cs| {
-        for line in &lines {
-            g.draw_line(cs.get_def("parking line", Color::WHITE), 0.25, line);
-        }
-    })
+    markings
 }
 
 fn calculate_driving_lines(lane: &Lane, parent: &Road) -> Option<Marking> {
@@ -213,11 +436,13 @@ fn calculate_driving_lines(lane: &Lane, parent: &Road) -> Option<Marking> {
         .0
         .dashed_polygons(0.25, dash_len, dash_separation);
 
-    Some(Box::new(move |g, cs| {
-        for p in &polygons {
-            g.draw_polygon(cs.get_def("dashed lane line", Color::WHITE), p);
-        }
-    }))
+    Some(Marking::DashedLine {
+        polygons,
+        color_key: "dashed lane line",
+        default_color: Color::WHITE,
+        feature_type: "dashed lane line",
+        min_zoom: MIN_ZOOM_FOR_DETAILED_MARKINGS,
+    })
 }
 
 fn calculate_stop_sign_line(road: &Road, lane: &Lane, map: &Map) -> Option<Marking> {
@@ -240,35 +465,121 @@ fn calculate_stop_sign_line(road: &Road, lane: &Lane, map: &Map) -> Option<Marki
         perp_line(Line::new(pt1, pt2), LANE_THICKNESS)
     };
 
-    Some(Box::new(move |g, cs| {
-        g.draw_line(cs.get_def("stop line for lane", Color::RED), 0.45, &line);
-    }))
+    Some(Marking::Line {
+        line,
+        thickness: 0.45,
+        rounded: false,
+        color_key: "stop line for lane",
+        default_color: Color::RED,
+        feature_type: "stop line",
+        min_zoom: MIN_ZOOM_FOR_MARKINGS,
+    })
 }
 
-fn calculate_turn_markings(map: &Map, lane: &Lane) -> Vec<Marking> {
-    let mut results: Vec<Marking> = Vec::new();
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum TurnDirection {
+    Left,
+    Through,
+    Right,
+}
+
+// Classifies a turn relative to the heading of the lane it leaves from, so multiple turns
+// sharing a direction can be drawn as a single arrowhead.
+fn classify_turn(lane_angle: Angle, turn_angle: Angle) -> TurnDirection {
+    let diff = (turn_angle.normalized_degrees() - lane_angle.normalized_degrees() + 360.0) % 360.0;
+    if diff < 45.0 || diff > 315.0 {
+        TurnDirection::Through
+    } else if diff <= 180.0 {
+        TurnDirection::Right
+    } else {
+        TurnDirection::Left
+    }
+}
+
+#[cfg(test)]
+mod turn_direction_tests {
+    use super::*;
+
+    #[test]
+    fn classify_turn_boundaries() {
+        let lane_angle = Angle::new_degs(0.0);
+        // Squarely ahead, and just shy of the left/right cutoffs, is a through turn.
+        assert_eq!(
+            classify_turn(lane_angle, lane_angle.rotate_degs(0.0)),
+            TurnDirection::Through
+        );
+        assert_eq!(
+            classify_turn(lane_angle, lane_angle.rotate_degs(44.0)),
+            TurnDirection::Through
+        );
+        assert_eq!(
+            classify_turn(lane_angle, lane_angle.rotate_degs(-44.0)),
+            TurnDirection::Through
+        );
+        // Past the cutoff on the positive side is a right turn, all the way to a U-turn.
+        assert_eq!(
+            classify_turn(lane_angle, lane_angle.rotate_degs(46.0)),
+            TurnDirection::Right
+        );
+        assert_eq!(
+            classify_turn(lane_angle, lane_angle.rotate_degs(180.0)),
+            TurnDirection::Right
+        );
+        // Just past the U-turn line wraps around to a left turn.
+        assert_eq!(
+            classify_turn(lane_angle, lane_angle.rotate_degs(181.0)),
+            TurnDirection::Left
+        );
+        assert_eq!(
+            classify_turn(lane_angle, lane_angle.rotate_degs(-46.0)),
+            TurnDirection::Left
+        );
+    }
+
+    // Builds both angles directly from degree literals instead of deriving turn_angle via
+    // rotate_degs on lane_angle, so a swapped Left/Right arm or a retuned cutoff would actually
+    // fail this test instead of passing along with the bug.
+    #[test]
+    fn classify_turn_from_absolute_angles() {
+        // Map y grows downward, so 90 degrees points south and 270 degrees points north.
+        // Heading east (0 degrees), turning onto a road heading south: a right turn.
+        assert_eq!(
+            classify_turn(Angle::new_degs(0.0), Angle::new_degs(90.0)),
+            TurnDirection::Right
+        );
+        // Heading east, turning onto a road heading north: a left turn.
+        assert_eq!(
+            classify_turn(Angle::new_degs(0.0), Angle::new_degs(270.0)),
+            TurnDirection::Left
+        );
+        // Heading south, continuing south: a through turn.
+        assert_eq!(
+            classify_turn(Angle::new_degs(90.0), Angle::new_degs(90.0)),
+            TurnDirection::Through
+        );
+    }
+}
 
+// One composite glyph per lane: a shared base bar, fanning out one arrowhead per distinct
+// turn direction, instead of a fully independent (and overlapping) arrow per turn. The glyph is
+// returned as separate markings: the base polygon first, then one arrow per direction.
+fn calculate_turn_markings(map: &Map, lane: &Lane) -> Vec<Marking> {
     // Are there multiple driving lanes on this side of the road?
     if map
         .find_closest_lane(lane.id, vec![LaneType::Driving])
         .is_err()
     {
-        return results;
+        return Vec::new();
     }
 
-    for turn in map.get_turns_from_lane(lane.id) {
-        if let Some(m) = turn_markings(turn, map) {
-            results.push(m);
-        }
+    let turns = map.get_turns_from_lane(lane.id);
+    if turns.is_empty() {
+        return Vec::new();
     }
-    results
-}
 
-fn turn_markings(turn: &Turn, map: &Map) -> Option<Marking> {
-    let lane = map.get_l(turn.id.src);
     let len = lane.length();
     if len < 7.0 * si::M {
-        return None;
+        return Vec::new();
     }
 
     let common_base = lane
@@ -276,16 +587,125 @@ fn turn_markings(turn: &Turn, map: &Map) -> Option<Marking> {
         .slice(len - 7.0 * si::M, len - 5.0 * si::M)
         .0;
     let base_polygon = common_base.make_polygons(0.1);
-    let turn_line = Line::new(
-        common_base.last_pt(),
-        common_base
-            .last_pt()
-            .project_away(LANE_THICKNESS / 2.0, turn.angle()),
-    );
-
-    Some(Box::new(move |g, cs| {
-        let color = cs.get_def("turn restrictions on lane", Color::WHITE);
-        g.draw_polygon(color, &base_polygon);
-        g.draw_arrow(color, 0.05, &turn_line);
-    }))
+    let base_pt = common_base.last_pt();
+    let (_, lane_angle) = lane.dist_along(len - 5.0 * si::M);
+
+    let mut has_left = false;
+    let mut has_through = false;
+    let mut has_right = false;
+    for turn in turns {
+        match classify_turn(lane_angle, turn.angle()) {
+            TurnDirection::Left => has_left = true,
+            TurnDirection::Through => has_through = true,
+            TurnDirection::Right => has_right = true,
+        }
+    }
+
+    let mut markings = vec![Marking::Polygon {
+        polygon: base_polygon,
+        color_key: "turn restrictions on lane",
+        default_color: Color::WHITE,
+        feature_type: "turn marking",
+        min_zoom: MIN_ZOOM_FOR_DETAILED_MARKINGS,
+    }];
+    // Fan the arrowheads left-to-right off the shared base, like painted lane arrows.
+    let mut fanned_angles = Vec::new();
+    if has_left {
+        fanned_angles.push(lane_angle.rotate_degs(-30.0));
+    }
+    if has_through {
+        fanned_angles.push(lane_angle);
+    }
+    if has_right {
+        fanned_angles.push(lane_angle.rotate_degs(30.0));
+    }
+    for angle in fanned_angles {
+        markings.push(Marking::Arrow {
+            line: Line::new(base_pt, base_pt.project_away(LANE_THICKNESS / 2.0, angle)),
+            thickness: 0.05,
+            color_key: "turn restrictions on lane",
+            default_color: Color::WHITE,
+            feature_type: "turn marking",
+            min_zoom: MIN_ZOOM_FOR_DETAILED_MARKINGS,
+        });
+    }
+    markings
+}
+
+#[cfg(test)]
+mod geojson_tests {
+    use super::*;
+
+    #[test]
+    fn close_ring_repeats_first_point_once() {
+        let pts = vec![vec![0.0, 0.0], vec![1.0, 0.0], vec![1.0, 1.0]];
+        let closed = close_ring(pts.clone());
+        assert_eq!(closed.len(), pts.len() + 1);
+        assert_eq!(closed.first(), closed.last());
+    }
+
+    #[test]
+    fn close_ring_is_a_no_op_if_already_closed() {
+        let pts = vec![vec![0.0, 0.0], vec![1.0, 0.0], vec![0.0, 0.0]];
+        assert_eq!(close_ring(pts.clone()), pts);
+    }
+
+    #[test]
+    fn line_marking_becomes_a_linestring_feature() {
+        let m = Marking::Line {
+            line: Line::new(Pt2D::new(0.0, 0.0), Pt2D::new(10.0, 0.0)),
+            thickness: 0.25,
+            rounded: false,
+            color_key: "test",
+            default_color: Color::WHITE,
+            feature_type: "stop line",
+            min_zoom: 0.0,
+        };
+        let f = m.to_geojson(&GPSBounds::new());
+        assert_eq!(
+            f.properties.unwrap().get("type").unwrap(),
+            &serde_json::Value::String("stop line".to_string())
+        );
+        match f.geometry.unwrap().value {
+            Value::LineString(pts) => assert_eq!(pts.len(), 2),
+            other => panic!("expected a LineString geometry, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn polygon_marking_becomes_a_closed_polygon_feature() {
+        let polygon = Line::new(Pt2D::new(0.0, 0.0), Pt2D::new(5.0, 0.0)).make_polygons(1.0);
+        let m = Marking::Polygon {
+            polygon,
+            color_key: "test",
+            default_color: Color::WHITE,
+            feature_type: "sidewalk corner",
+            min_zoom: 0.0,
+        };
+        let f = m.to_geojson(&GPSBounds::new());
+        match f.geometry.unwrap().value {
+            Value::Polygon(rings) => assert_eq!(rings[0].first(), rings[0].last()),
+            other => panic!("expected a Polygon geometry, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn dashed_line_marking_becomes_a_multipolygon_feature() {
+        let polygons = vec![
+            Line::new(Pt2D::new(0.0, 0.0), Pt2D::new(1.0, 0.0)).make_polygons(0.25),
+            Line::new(Pt2D::new(2.0, 0.0), Pt2D::new(3.0, 0.0)).make_polygons(0.25),
+        ];
+        let m = Marking::DashedLine {
+            polygons,
+            color_key: "test",
+            default_color: Color::WHITE,
+            feature_type: "dashed lane line",
+            min_zoom: 0.0,
+        };
+        let f = m.to_geojson(&GPSBounds::new());
+        match f.geometry.unwrap().value {
+            Value::MultiPolygon(polys) => assert_eq!(polys.len(), 2),
+            other => panic!("expected a MultiPolygon geometry, got {:?}", other),
+        }
+    }
 }
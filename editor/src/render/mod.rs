@@ -0,0 +1,5 @@
+mod intersection;
+mod lane;
+
+pub use self::intersection::DrawIntersection;
+pub use self::lane::DrawLane;